@@ -145,8 +145,8 @@ pub enum BatchError {
     #[error("Streaming vnode mapping not found for fragment {0}")]
     StreamingVnodeMappingNotFound(FragmentId),
 
-    #[error("Not enough memory to run this query, batch memory limit is {0} bytes")]
-    OutOfMemory(u64),
+    #[error("Not enough memory to run this query, used {0} bytes, batch memory limit is {1} bytes")]
+    OutOfMemory(u64, u64),
 
     #[error("Failed to spill out to disk")]
     Spill(
@@ -582,7 +582,10 @@ impl<K: HashKey + Send + Sync> HashAggExecutor<K> {
                     need_to_spill = true;
                     break;
                 } else {
-                    Err(BatchError::OutOfMemory(self.mem_context.mem_limit()))?;
+                    Err(BatchError::OutOfMemory(
+                        self.mem_context.get_bytes_used() as u64,
+                        self.mem_context.mem_limit(),
+                    ))?;
                 }
             }
         }
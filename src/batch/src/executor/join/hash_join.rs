@@ -494,7 +494,10 @@ impl<K: HashKey> HashJoinExecutor<K> {
                         need_to_spill = true;
                         break;
                     } else {
-                        Err(BatchError::OutOfMemory(self.mem_ctx.mem_limit()))?;
+                        Err(BatchError::OutOfMemory(
+                            self.mem_ctx.get_bytes_used() as u64,
+                            self.mem_ctx.mem_limit(),
+                        ))?;
                     }
                 }
             }
@@ -534,7 +537,10 @@ impl<K: HashKey> HashJoinExecutor<K> {
                                 need_to_spill = true;
                                 break;
                             } else {
-                                Err(BatchError::OutOfMemory(self.mem_ctx.mem_limit()))?;
+                                Err(BatchError::OutOfMemory(
+                                    self.mem_ctx.get_bytes_used() as u64,
+                                    self.mem_ctx.mem_limit(),
+                                ))?;
                             }
                         }
                         next_build_row_with_same_key[row_id] = hash_map.insert(build_key, row_id);
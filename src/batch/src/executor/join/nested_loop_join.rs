@@ -99,7 +99,10 @@ impl NestedLoopJoinExecutor {
                 let c = chunk?;
                 trace!("Estimated chunk size is {:?}", c.estimated_heap_size());
                 if !self.mem_context.add(c.estimated_heap_size() as i64) {
-                    Err(BatchError::OutOfMemory(self.mem_context.mem_limit()))?;
+                    Err(BatchError::OutOfMemory(
+                        self.mem_context.get_bytes_used() as u64,
+                        self.mem_context.mem_limit(),
+                    ))?;
                 }
                 ret.push(c);
             }
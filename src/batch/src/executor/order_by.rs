@@ -135,7 +135,10 @@ impl SortExecutor {
                     need_to_spill = true;
                     break;
                 } else {
-                    Err(BatchError::OutOfMemory(self.mem_context.mem_limit()))?;
+                    Err(BatchError::OutOfMemory(
+                        self.mem_context.get_bytes_used() as u64,
+                        self.mem_context.mem_limit(),
+                    ))?;
                 }
             }
         }
@@ -160,7 +163,10 @@ impl SortExecutor {
                     need_to_spill = true;
                     break;
                 } else {
-                    Err(BatchError::OutOfMemory(self.mem_context.mem_limit()))?;
+                    Err(BatchError::OutOfMemory(
+                        self.mem_context.get_bytes_used() as u64,
+                        self.mem_context.mem_limit(),
+                    ))?;
                 }
             }
         }
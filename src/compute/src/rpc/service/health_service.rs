@@ -16,7 +16,6 @@ use risingwave_pb::health::health_check_response::ServingStatus;
 use risingwave_pb::health::health_server::Health;
 use risingwave_pb::health::{HealthCheckRequest, HealthCheckResponse};
 use tonic::{Request, Response, Status};
-
 pub struct HealthServiceImpl {}
 
 impl HealthServiceImpl {
@@ -36,6 +36,9 @@ pub async fn update_compaction_config(
     ids: Vec<CompactionGroupId>,
     configs: Vec<MutableConfig>,
 ) -> anyhow::Result<()> {
+    if configs.is_empty() {
+        anyhow::bail!("no compaction config field specified, nothing to update");
+    }
     let meta_client = context.meta_client().await?;
     meta_client
         .risectl_update_compaction_config(ids.as_slice(), configs.as_slice())
@@ -44,7 +44,6 @@ use crate::common::HummockServiceOpts;
 use crate::CtlContext;
 
 type TableData = HashMap<u32, TableCatalog>;
-
 #[derive(Args, Debug)]
 pub struct SstDumpArgs {
     #[clap(short, long = "object-id")]
@@ -62,7 +61,6 @@ pub struct SstDumpArgs {
     #[clap(short, long = "use-new-object-prefix-strategy", default_value = "true")]
     use_new_object_prefix_strategy: bool,
 }
-
 pub async fn sst_dump(context: &CtlContext, args: SstDumpArgs) -> anyhow::Result<()> {
     println!("Start sst dump with args: {:?}", args);
     let table_data = if args.print_entry && args.print_table {
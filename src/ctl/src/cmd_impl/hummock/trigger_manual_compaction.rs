@@ -15,7 +15,6 @@
 use risingwave_rpc_client::HummockMetaClient;
 
 use crate::CtlContext;
-
 pub async fn trigger_manual_compaction(
     context: &CtlContext,
     compaction_group_id: u64,
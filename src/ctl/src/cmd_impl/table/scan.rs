@@ -85,7 +85,6 @@ pub fn make_storage_table<S: StateStore>(
         &table.table_desc().try_to_protobuf()?,
     ))
 }
-
 pub async fn scan(
     context: &CtlContext,
     mv_name: String,
@@ -23,7 +23,6 @@ use super::OwnedByUserCatalog;
 use crate::catalog::schema_catalog::SchemaCatalog;
 use crate::catalog::{DatabaseId, SchemaId, TableId};
 use crate::user::UserId;
-
 #[derive(Clone, Debug)]
 pub struct DatabaseCatalog {
     id: DatabaseId,
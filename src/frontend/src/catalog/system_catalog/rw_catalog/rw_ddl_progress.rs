@@ -19,7 +19,6 @@ use risingwave_frontend_macro::system_catalog;
 
 use crate::catalog::system_catalog::SysCatalogReaderImpl;
 use crate::error::Result;
-
 #[derive(Fields)]
 struct RwDdlProgress {
     #[primary_key]
@@ -28,7 +28,6 @@ use crate::catalog::table_catalog::TableType;
 use crate::catalog::CatalogError;
 use crate::error::{ErrorCode, Result};
 use crate::Binder;
-
 pub async fn handle_alter_parallelism(
     handler_args: HandlerArgs,
     obj_name: ObjectName,
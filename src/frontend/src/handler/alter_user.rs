@@ -23,12 +23,14 @@ use crate::catalog::CatalogError;
 use crate::error::ErrorCode::{self, InternalError, PermissionDenied};
 use crate::error::Result;
 use crate::handler::HandlerArgs;
+use crate::session::SessionImpl;
 use crate::user::user_authentication::{
     build_oauth_info, encrypted_password, OAUTH_ISSUER_KEY, OAUTH_JWKS_URL_KEY,
 };
 use crate::user::user_catalog::UserCatalog;
 
 fn alter_prost_user_info(
+    session: &SessionImpl,
     mut user_info: UserInfo,
     options: &UserOptions,
     session_user: &UserCatalog,
@@ -94,21 +96,24 @@ fn alter_prost_user_info(
                 update_fields.push(UpdateField::Login);
             }
             UserOption::EncryptedPassword(p) => {
-                // TODO: Behaviour of PostgreSQL: Notice when password is empty string.
                 if !p.0.is_empty() {
                     user_info.auth_info = encrypted_password(&user_info.name, &p.0);
                 } else {
+                    session.notice_to_user("empty string is not a valid password, clearing password");
                     user_info.auth_info = None;
                 };
                 update_fields.push(UpdateField::AuthInfo);
             }
             UserOption::Password(opt) => {
-                // TODO: Behaviour of PostgreSQL: Notice when password is empty string.
                 if let Some(password) = opt
                     && !password.0.is_empty()
                 {
                     user_info.auth_info = encrypted_password(&user_info.name, &password.0);
                 } else {
+                    if opt.is_some() {
+                        session
+                            .notice_to_user("empty string is not a valid password, clearing password");
+                    }
                     user_info.auth_info = None;
                 }
                 update_fields.push(UpdateField::AuthInfo);
@@ -176,7 +181,7 @@ pub async fn handle_alter_user(
 
         match stmt.mode {
             risingwave_sqlparser::ast::AlterUserMode::Options(options) => {
-                alter_prost_user_info(old_info, &options, session_user)?
+                alter_prost_user_info(&session, old_info, &options, session_user)?
             }
             risingwave_sqlparser::ast::AlterUserMode::Rename(new_name) => {
                 alter_rename_prost_user_info(old_info, new_name, session_user)?
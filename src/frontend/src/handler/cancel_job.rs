@@ -20,7 +20,6 @@ use risingwave_sqlparser::ast::JobIdents;
 use super::RwPgResponseBuilderExt;
 use crate::error::Result;
 use crate::handler::{HandlerArgs, RwPgResponse};
-
 pub(super) async fn handle_cancel(
     handler_args: HandlerArgs,
     jobs: JobIdents,
@@ -23,12 +23,14 @@ use crate::catalog::{CatalogError, DatabaseId};
 use crate::error::ErrorCode::{self, PermissionDenied};
 use crate::error::Result;
 use crate::handler::HandlerArgs;
+use crate::session::SessionImpl;
 use crate::user::user_authentication::{
     build_oauth_info, encrypted_password, OAUTH_ISSUER_KEY, OAUTH_JWKS_URL_KEY,
 };
 use crate::user::user_catalog::UserCatalog;
 
 fn make_prost_user_info(
+    session: &SessionImpl,
     user_name: String,
     options: &UserOptions,
     session_user: &UserCatalog,
@@ -80,17 +82,19 @@ fn make_prost_user_info(
             UserOption::Login => user_info.can_login = true,
             UserOption::NoLogin => user_info.can_login = false,
             UserOption::EncryptedPassword(password) => {
-                // TODO: Behaviour of PostgreSQL: Notice when password is empty string.
                 if !password.0.is_empty() {
                     user_info.auth_info = encrypted_password(&user_info.name, &password.0);
+                } else {
+                    session.notice_to_user("empty string is not a valid password, clearing password");
                 }
             }
             UserOption::Password(opt) => {
-                // TODO: Behaviour of PostgreSQL: Notice when password is empty string.
                 if let Some(password) = opt
                     && !password.0.is_empty()
                 {
                     user_info.auth_info = encrypted_password(&user_info.name, &password.0);
+                } else if opt.is_some() {
+                    session.notice_to_user("empty string is not a valid password, clearing password");
                 }
             }
             UserOption::OAuth(options) => {
@@ -131,7 +135,7 @@ pub async fn handle_create_user(
             .get_user_by_name(session.user_name())
             .ok_or_else(|| CatalogError::NotFound("user", session.user_name().to_string()))?;
 
-        make_prost_user_info(user_name, &stmt.with_options, session_user, database_id)?
+        make_prost_user_info(&session, user_name, &stmt.with_options, session_user, database_id)?
     };
 
     let user_info_writer = session.user_info_writer()?;
@@ -23,7 +23,6 @@ pub(super) async fn handle_flush(handler_args: HandlerArgs) -> Result<RwPgRespon
     do_flush(&handler_args.session).await?;
     Ok(PgResponse::empty_result(StatementType::FLUSH))
 }
-
 pub(crate) async fn do_flush(session: &SessionImpl) -> Result<()> {
     let client = session.env().meta_client();
     let snapshot = client.flush(true).await?;
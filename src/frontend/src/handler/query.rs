@@ -352,7 +352,6 @@ pub fn gen_batch_plan_fragmenter(
         _dependent_relations: dependent_relations,
     })
 }
-
 pub async fn create_stream(
     session: Arc<SessionImpl>,
     plan_fragmenter_result: BatchPlanFragmenterResult,
@@ -412,7 +411,6 @@ pub async fn create_stream(
 
     Ok((row_stream, pg_descs))
 }
-
 async fn execute(
     session: Arc<SessionImpl>,
     plan_fragmenter_result: BatchPlanFragmenterResult,
@@ -32,6 +32,11 @@
 //!
 //! Given index order key (a, b, c)
 //!
+//! `a IS NULL` is treated the same as `a = 1` (`Equal`), since it also pins the
+//! column to a single value on the index. This matching is done by [`TableScanIoEstimator`];
+//! the rewrite of a scan into an index scan is done by [`IndexSelectionRule`] in this module,
+//! while `CREATE INDEX` itself is handled in [`crate::handler::create_index`].
+//!
 //! - For `a = 1 and b = 1 and c = 1`, its cost is 1 = Equal0 * Equal1 * Equal2 = 1
 //! - For `a in (xxx) and b = 1 and c = 1`, its cost is In0 * Equal1 * Equal2 = 10
 //! - For `a = 1 and b in (xxx)`, its cost is Equal0 * In1 * All2 = 1 * 8 * 50 = 400
@@ -840,6 +845,16 @@ impl<'a> TableScanIoEstimator<'a> {
             }
         }
 
+        // `IS NULL` pins the column to a single value on the index, just like equality.
+        for (i, expr) in conjunctions.iter().enumerate() {
+            if let Some(input_ref) = expr.as_is_null()
+                && input_ref.index == column_idx
+            {
+                conjunctions.remove(i);
+                return MatchItem::Equal;
+            }
+        }
+
         // In
         for (i, expr) in conjunctions.iter().enumerate() {
             if let Some((input_ref, in_const_list)) = expr.as_in_const_list()
@@ -208,6 +208,15 @@ impl ExpressionSimplifyRewriter {
 
 impl ExprRewriter for ExpressionSimplifyRewriter {
     fn rewrite_expr(&mut self, expr: ExprImpl) -> ExprImpl {
+        // Recurse into the children first, so patterns nested inside e.g. `NOT(...)` or a
+        // deeper `AND`/`OR` branch (`(a OR b) OR c`) get simplified as well, not just the
+        // expression at the very top of this conjunction.
+        let expr = if let ExprImpl::FunctionCall(func_call) = expr {
+            self.rewrite_function_call(*func_call)
+        } else {
+            expr
+        };
+
         // Check if the input expression is *definitely* null
         let mut columns = vec![];
         extract_column(expr.clone(), &mut columns);
@@ -1098,7 +1098,6 @@ impl SessionImpl {
         tracing::trace!("notice to user:{}", notice);
         self.notices.write().push(notice);
     }
-
     pub fn is_barrier_read(&self) -> bool {
         match self.config().visibility_mode() {
             VisibilityMode::Default => self.env.batch_config.enable_barrier_read,
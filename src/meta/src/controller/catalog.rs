@@ -374,22 +374,66 @@ impl CatalogController {
         &self,
         schema_id: SchemaId,
         drop_mode: DropMode,
-    ) -> MetaResult<NotificationVersion> {
+    ) -> MetaResult<(ReleaseContext, NotificationVersion)> {
         let inner = self.inner.write().await;
         let txn = inner.db.begin().await?;
         let schema_obj = Object::find_by_id(schema_id)
             .one(&txn)
             .await?
             .ok_or_else(|| MetaError::catalog_id_not_found("schema", schema_id))?;
-        // TODO: support drop schema cascade.
         if drop_mode == DropMode::Restrict {
             ensure_schema_empty(schema_id, &txn).await?;
-        } else {
-            return Err(MetaError::permission_denied(
-                "drop schema cascade is not supported yet".to_string(),
-            ));
         }
 
+        // Cascade-drop mirrors `drop_database`: collect the streaming jobs and other
+        // resources owned by objects in this schema so the caller can release them
+        // (unregister sources, drop actors/fragments, tear down vpc endpoints) before
+        // the object rows themselves are removed by the database's cascading FKs.
+        let streaming_jobs: Vec<ObjectId> = StreamingJob::find()
+            .join(JoinType::InnerJoin, streaming_job::Relation::Object.def())
+            .select_only()
+            .column(streaming_job::Column::JobId)
+            .filter(object::Column::SchemaId.eq(Some(schema_id)))
+            .into_tuple()
+            .all(&txn)
+            .await?;
+
+        let (source_fragments, removed_actors, removed_fragments) =
+            resolve_source_register_info_for_jobs(&txn, streaming_jobs.clone()).await?;
+
+        let state_table_ids: Vec<TableId> = Table::find()
+            .select_only()
+            .column(table::Column::TableId)
+            .filter(
+                table::Column::BelongsToJobId
+                    .is_in(streaming_jobs.clone())
+                    .or(table::Column::TableId.is_in(streaming_jobs.clone())),
+            )
+            .into_tuple()
+            .all(&txn)
+            .await?;
+
+        let source_ids: Vec<SourceId> = Object::find()
+            .select_only()
+            .column(object::Column::Oid)
+            .filter(
+                object::Column::SchemaId
+                    .eq(Some(schema_id))
+                    .and(object::Column::ObjType.eq(ObjectType::Source)),
+            )
+            .into_tuple()
+            .all(&txn)
+            .await?;
+
+        let connections = Connection::find()
+            .inner_join(Object)
+            .filter(object::Column::SchemaId.eq(Some(schema_id)))
+            .all(&txn)
+            .await?
+            .into_iter()
+            .map(|conn| conn.info)
+            .collect_vec();
+
         // Find affect users with privileges on the schema and the objects in the schema.
         let to_update_user_ids: Vec<UserId> = UserPrivilege::find()
             .select_only()
@@ -405,6 +449,16 @@ impl CatalogController {
             .all(&txn)
             .await?;
 
+        let fragment_mappings = get_fragment_ids_by_jobs(&txn, streaming_jobs.clone())
+            .await?
+            .into_iter()
+            .map(|fragment_id| PbFragmentWorkerSlotMapping {
+                fragment_id: fragment_id as _,
+                mapping: None,
+            })
+            .collect();
+
+        // The objects in the schema will be deleted cascade.
         let res = Object::delete(object::ActiveModel {
             oid: Set(schema_id),
             ..Default::default()
@@ -429,7 +483,21 @@ impl CatalogController {
                 }),
             )
             .await;
-        Ok(version)
+
+        self.notify_fragment_mapping(NotificationOperation::Delete, fragment_mappings)
+            .await;
+        Ok((
+            ReleaseContext {
+                streaming_job_ids: streaming_jobs,
+                state_table_ids,
+                source_ids,
+                connections,
+                source_fragments,
+                removed_actors,
+                removed_fragments,
+            },
+            version,
+        ))
     }
 
     pub async fn create_subscription_catalog(
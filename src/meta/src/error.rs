@@ -163,7 +163,6 @@ where
         MetaErrorInner::Aws(e.into()).into()
     }
 }
-
 impl From<MetaError> for tonic::Status {
     fn from(err: MetaError) -> Self {
         use tonic::Code;
@@ -62,7 +62,6 @@ pub struct CompactionSelectorContext<'a> {
     pub table_watermarks: &'a HashMap<TableId, Arc<TableWatermarks>>,
     pub state_table_info: &'a HummockVersionStateTableInfo,
 }
-
 pub trait CompactionSelector: Sync + Send {
     fn pick_compaction(
         &mut self,
@@ -19,7 +19,6 @@ use risingwave_hummock_sdk::level::Level;
 use risingwave_hummock_sdk::sstable_info::SstableInfo;
 use risingwave_hummock_sdk::{HummockCompactionTaskId, HummockSstableId};
 use risingwave_pb::hummock::level_handler::RunningCompactTask;
-
 #[derive(Clone, Debug, PartialEq)]
 pub struct LevelHandler {
     level: u32,
@@ -52,7 +52,6 @@ pub struct NewTableFragmentInfo {
     pub mv_table_id: Option<TableId>,
     pub internal_table_ids: Vec<TableId>,
 }
-
 pub struct CommitEpochInfo {
     pub sstables: Vec<LocalSstableInfo>,
     pub new_table_watermarks: HashMap<TableId, TableWatermarks>,
@@ -115,8 +114,6 @@ impl HummockManager {
         self.commit_epoch(info).await?;
         Ok(())
     }
-
-    /// Caller should ensure `epoch` > `max_committed_epoch`
     pub async fn commit_epoch(
         &self,
         commit_info: CommitEpochInfo,
@@ -170,12 +170,19 @@ impl HummockManager {
         to_delete.len()
     }
 
-    /// Starts a full GC.
+    /// Starts a full GC (vacuum) pass over orphaned objects: SSTs left behind by compaction
+    /// outputs that never got registered into a `HummockVersion`, or that fell out of every live
+    /// version/pinned snapshot's reachable set.
     /// 1. Meta node sends a `FullScanTask` to a compactor in this method.
     /// 2. The compactor returns scan result of object store to meta node. See
     ///    `HummockManager::full_scan_inner` in storage crate.
     /// 3. Meta node decides which SSTs to delete. See `HummockManager::complete_full_gc`.
     ///
+    /// `sst_retention_time` (floored by the `min_sst_retention_time_sec` config) is the grace
+    /// period below which a candidate is left alone even if unreferenced, guarding against
+    /// racing with an object whose upload just completed but whose version registration hasn't
+    /// landed yet.
+    ///
     /// Returns Ok(false) if there is no worker available.
     pub fn start_full_gc(
         &self,
@@ -472,9 +472,6 @@ impl ClusterManager {
         let core = self.core.read().await;
         core.list_streaming_worker_node(Some(State::Running))
     }
-
-    /// Get the cluster info used for scheduling a streaming job, containing all nodes that are
-    /// running and schedulable
     pub async fn list_active_serving_compute_nodes(&self) -> Vec<WorkerNode> {
         let core = self.core.read().await;
         core.list_serving_worker_node(Some(State::Running))
@@ -244,10 +244,9 @@ impl DdlController {
         let (release_ctx, mut version) = match object_type {
             ObjectType::Database => mgr.catalog_controller.drop_database(object_id).await?,
             ObjectType::Schema => {
-                return mgr
-                    .catalog_controller
+                mgr.catalog_controller
                     .drop_schema(object_id, drop_mode)
-                    .await;
+                    .await?
             }
             ObjectType::Function => {
                 return mgr.catalog_controller.drop_function(object_id).await;
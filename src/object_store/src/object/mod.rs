@@ -1008,7 +1008,6 @@ pub async fn build_remote_object_store(
         }
     }
 }
-
 #[inline(always)]
 fn get_retry_strategy(
     config: &ObjectStoreConfig,
@@ -25,7 +25,6 @@ use crate::object::opendal_engine::ATOMIC_WRITE_DIR;
 use crate::object::ObjectResult;
 
 impl OpendalObjectStore {
-    /// create opendal fs engine.
     pub fn new_fs_engine(
         root: String,
         config: Arc<ObjectStoreConfig>,
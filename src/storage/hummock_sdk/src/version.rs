@@ -202,7 +202,6 @@ impl HummockVersionStateTableInfo {
         &self.compaction_group_member_tables
     }
 }
-
 #[derive(Debug, Clone, PartialEq)]
 pub struct HummockVersion {
     pub id: u64,
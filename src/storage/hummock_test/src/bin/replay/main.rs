@@ -83,7 +83,9 @@ async fn run_replay(args: Args) -> Result<()> {
     let r: Record = reader.read().unwrap();
     let replay_interface = create_replay_hummock(r, &args).await.unwrap();
     let mut replayer = HummockReplay::new(reader, replay_interface);
+    let start_time = std::time::Instant::now();
     replayer.run().await.unwrap();
+    tracing::info!("replay of {} finished in {:?}", args.path, start_time.elapsed());
 
     Ok(())
 }
@@ -16,7 +16,6 @@ use std::collections::{HashMap, HashSet};
 
 use dyn_clone::DynClone;
 use risingwave_hummock_sdk::key::FullKey;
-
 pub trait CompactionFilter: Send + Sync + DynClone {
     fn should_delete(&mut self, _: FullKey<&[u8]>) -> bool {
         false
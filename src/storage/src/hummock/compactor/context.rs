@@ -56,7 +56,6 @@ pub struct CompactorContext {
     pub is_share_buffer_compact: bool,
 
     pub compaction_executor: Arc<CompactionExecutor>,
-
     pub memory_limiter: Arc<MemoryLimiter>,
 
     pub task_progress_manager: TaskProgressManagerRef,
@@ -242,7 +242,6 @@ impl Compactor {
             sstable_writer_factory: writer_factory,
             _phantom: PhantomData,
         };
-
         let mut sst_builder = CapacitySplitTableBuilder::new(
             builder_factory,
             self.context.compactor_metrics.clone(),
@@ -77,7 +77,11 @@ impl ConflictDetector {
 
     /// Checks whether there is key conflict for the given `kv_pairs` and adds the key in `kv_pairs`
     /// to the tracking history. Besides, whether the `epoch` has been archived will also be checked
-    /// to avoid writing to a stale epoch
+    /// to avoid writing to a stale epoch.
+    ///
+    /// Called from `HummockEventHandler::handle_hummock_event`'s `ImmToUploader` arm via
+    /// [`crate::hummock::shared_buffer::shared_buffer_batch::SharedBufferBatch::get_payload`], so
+    /// every batch handed to the uploader is checked for same-epoch key collisions.
     pub fn check_conflict_and_track_write_batch(
         &self,
         kv_pairs: &[(Bytes, HummockValue<Bytes>)],
@@ -756,6 +756,10 @@ impl HummockEventHandler {
                     instance_id,
                     imm.table_id,
                 );
+                if let Some(conflict_detector) = self.write_conflict_detector.as_ref() {
+                    conflict_detector
+                        .check_conflict_and_track_write_batch(&imm.get_payload(), imm.max_epoch());
+                }
                 self.uploader.add_imm(instance_id, imm);
                 self.uploader.may_flush();
             }
@@ -50,7 +50,6 @@ pub enum HummockVersionUpdate {
     VersionDeltas(Vec<HummockVersionDelta>),
     PinnedVersion(Box<HummockVersion>),
 }
-
 pub enum HummockEvent {
     /// Notify that we may flush the shared buffer.
     BufferMayFlush,
@@ -417,6 +417,28 @@ impl SharedBufferBatch {
         self.inner.old_values.is_some()
     }
 
+    /// Returns the latest key-value pair for every key currently held by this batch, in key
+    /// order, without consuming it (unlike [`Self::into_forward_iter`]).
+    ///
+    /// Used by [`crate::hummock::conflict_detector::ConflictDetector`] to check for same-epoch
+    /// key collisions right after a batch is handed to the uploader. Callers pair this with
+    /// [`Self::max_epoch`] as the single epoch for the whole payload, which is only sound because
+    /// every batch reaching the uploader is still single-epoch at that point (built from one
+    /// `LocalHummockStorage::epoch()` in `flush()`); [`Self::new_with_multi_epoch_batches`] only
+    /// runs later, in the compactor, to merge batches that have already passed conflict detection
+    /// individually.
+    pub(crate) fn get_payload(&self) -> Vec<(Bytes, HummockValue<Bytes>)> {
+        self.inner
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                let (_, value) = &self.inner.values(i)[0];
+                (entry.key.0.clone(), value.clone().into())
+            })
+            .collect()
+    }
+
     pub fn get(
         &self,
         table_key: TableKey<&[u8]>,
@@ -116,7 +116,6 @@ impl BlockIterator {
         self.seek_restart_point_by_index(self.block.restart_point_len() - 1);
         self.next_until_prev_offset(self.block.len());
     }
-
     pub fn seek(&mut self, key: FullKey<&[u8]>) {
         self.seek_restart_point_by_key(key);
         self.next_until_key(key);
@@ -114,7 +114,6 @@ impl BloomFilterReader {
         }
     }
 }
-
 pub struct BloomFilterBuilder {
     key_hash_entries: Vec<u32>,
     bits_per_key: usize,
@@ -43,7 +43,6 @@ pub const DEFAULT_SSTABLE_SIZE: usize = 4 * 1024 * 1024;
 pub const DEFAULT_BLOOM_FALSE_POSITIVE: f64 = 0.001;
 pub const DEFAULT_MAX_SST_SIZE: u64 = 512 * 1024 * 1024;
 pub const MIN_BLOCK_SIZE: usize = 8 * 1024;
-
 #[derive(Clone, Debug)]
 pub struct SstableBuilderOptions {
     /// Approximate sstable capacity.
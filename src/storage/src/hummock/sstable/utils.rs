@@ -84,7 +84,6 @@ pub fn get_length_prefixed_slice(buf: &mut &[u8]) -> Vec<u8> {
     buf.advance(len);
     v
 }
-
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum CompressionAlgorithm {
     None,
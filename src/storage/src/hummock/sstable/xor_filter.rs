@@ -29,7 +29,6 @@ const FOOTER_XOR8: u8 = 254;
 const FOOTER_XOR16: u8 = 255;
 const FOOTER_BLOCKED_XOR16: u8 = 253;
 const MAX_KV_COUNT_FOR_XOR16: usize = 256 * 1024;
-
 pub struct Xor16FilterBuilder {
     key_hash_entries: Vec<u64>,
 }
@@ -323,7 +323,6 @@ impl HummockStorage {
             .rev_iter(key_range, epoch, read_options, read_version_tuple, None)
             .await
     }
-
     async fn build_read_version_by_time_travel(
         &self,
         epoch: u64,
@@ -567,7 +566,6 @@ impl StateStoreRead for HummockStorage {
         );
         self.iter_inner(key_range, epoch, read_options)
     }
-
     fn rev_iter(
         &self,
         key_range: TableKeyRange,
@@ -142,7 +142,6 @@ pub enum VersionUpdate {
         vnode_watermarks: Vec<VnodeWatermark>,
     },
 }
-
 #[derive(Clone)]
 pub struct StagingVersion {
     // newer data comes first
@@ -135,12 +135,19 @@ where
 
 /// Prune non-overlapping SSTs that does not overlap with a specific key range or does not overlap
 /// with a specific table id. Returns the sst ids after pruning.
+///
+/// The key-range-aware pruning itself predates this file's history; the `ssts.is_empty()` guard
+/// below only fixes a panic (`ssts[0..=0]` on an empty slice via the `saturating_sub(1)` index
+/// math) when called with no SSTs.
 #[allow(clippy::type_complexity)]
 pub fn prune_nonoverlapping_ssts<'a>(
     ssts: &'a [SstableInfo],
     user_key_range: (Bound<UserKey<&'a [u8]>>, Bound<UserKey<&'a [u8]>>),
 ) -> impl DoubleEndedIterator<Item = &'a SstableInfo> {
     debug_assert!(can_concat(ssts));
+    if ssts.is_empty() {
+        return ssts.iter();
+    }
     let start_table_idx = match user_key_range.0 {
         Included(key) | Excluded(key) => search_sst_idx(ssts, key).saturating_sub(1),
         _ => 0,
@@ -17,7 +17,6 @@ use risingwave_common::config::{
 };
 use risingwave_common::system_param::reader::{SystemParamsRead, SystemParamsReader};
 use risingwave_common::system_param::system_params_for_test;
-
 #[derive(Clone, Debug)]
 pub struct StorageOpts {
     /// The size of parallel task for one compact/flush job.
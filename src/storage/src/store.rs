@@ -340,7 +340,6 @@ pub trait StateStoreWrite: StaticSendSync {
 }
 
 pub trait SyncFuture = Future<Output = StorageResult<SyncResult>> + Send + 'static;
-
 pub trait StateStore: StateStoreRead + StaticSendSync + Clone {
     type Local: LocalStateStore;
 
@@ -350,7 +349,6 @@ pub trait StateStore: StateStoreRead + StaticSendSync + Clone {
         &self,
         epoch: HummockReadEpoch,
     ) -> impl Future<Output = StorageResult<()>> + Send + '_;
-
     fn sync(&self, epoch: u64, table_ids: HashSet<TableId>) -> impl SyncFuture;
 
     /// update max current epoch in storage.
@@ -540,7 +538,6 @@ impl From<ReadOptions> for TracedReadOptions {
         }
     }
 }
-
 pub fn gen_min_epoch(base_epoch: u64, retention_seconds: Option<&u32>) -> u64 {
     let base_epoch = Epoch(base_epoch);
     match retention_seconds {
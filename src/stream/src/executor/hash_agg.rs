@@ -494,8 +494,6 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
 
         // Flush distinct dedup state.
         vars.distinct_dedup.flush(&mut this.distinct_dedup_tables)?;
-
-        // Evict cache to target capacity.
         vars.agg_group_cache.evict();
     }
 
@@ -23,7 +23,7 @@ use tokio::sync::mpsc;
 use super::error::StreamExecutorError;
 use super::{
     Barrier, BoxedMessageStream, Execute, Executor, ExecutorInfo, Message, MessageStream,
-    StreamChunk, StreamExecutorResult, Watermark,
+    Mutation, StreamChunk, StreamExecutorResult, Watermark,
 };
 
 pub mod prelude {
@@ -87,6 +87,15 @@ impl MessageSender {
         self.0.send(Message::Barrier(barrier)).unwrap();
     }
 
+    #[allow(dead_code)]
+    pub fn push_barrier_with_mutation(&mut self, epoch: u64, stop: bool, mutation: Mutation) {
+        let mut barrier = Barrier::new_test_barrier(epoch).with_mutation(mutation);
+        if stop {
+            barrier = barrier.with_stop();
+        }
+        self.0.send(Message::Barrier(barrier)).unwrap();
+    }
+
     #[allow(dead_code)]
     pub fn push_watermark(&mut self, col_idx: usize, data_type: DataType, val: ScalarImpl) {
         self.0
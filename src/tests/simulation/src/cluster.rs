@@ -950,4 +950,14 @@ impl KillOpts {
         kill_compactor: true,
         restart_delay_secs: 2,
     };
+    /// Only killing compute nodes, useful for recovery tests that want to isolate the effect of
+    /// losing streaming actors without also exercising meta/frontend/compactor failover.
+    pub const COMPUTE_ONLY: Self = KillOpts {
+        kill_rate: 1.0,
+        kill_meta: false,
+        kill_frontend: false,
+        kill_compute: true,
+        kill_compactor: false,
+        restart_delay_secs: 20,
+    };
 }
@@ -14,6 +14,7 @@
 
 mod backfill;
 mod background_ddl;
+mod compute_node_kill;
 mod event_log;
 mod nexmark_recovery;
 mod pause_on_bootstrap;
@@ -152,7 +152,6 @@ impl Drop for ExecContext {
         *self.last_idle_instant.lock() = Some(Instant::now());
     }
 }
-
 #[derive(Debug, Clone)]
 pub enum UserAuthenticator {
     // No need to authenticate.